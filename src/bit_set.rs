@@ -0,0 +1,177 @@
+use std::marker::PhantomData;
+
+use crate::Idx;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+#[inline]
+fn num_words(domain_size: usize) -> usize {
+    domain_size.div_ceil(WORD_BITS)
+}
+
+/// A bit-set over the same key space as a [`TypeKeyVec`](crate::TypeKeyVec)
+///
+/// Used for compact membership tests (liveness, visited-sets, reachability) keyed by a newtype
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TypeKeyBitSet<K> {
+    domain_size: usize,
+    words: Vec<u64>,
+    phantom: PhantomData<K>,
+}
+
+impl<K> TypeKeyBitSet<K>
+where
+    K: Idx,
+{
+    #[inline]
+    pub fn new_empty(domain_size: usize) -> Self {
+        Self {
+            domain_size,
+            words: vec![0; num_words(domain_size)],
+            phantom: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn new_filled(domain_size: usize) -> Self {
+        let mut set = Self::new_empty(domain_size);
+        set.words.fill(u64::MAX);
+        set.clear_excess_bits();
+        set
+    }
+
+    #[inline]
+    pub fn domain_size(&self) -> usize {
+        self.domain_size
+    }
+
+    /// Inserts `elem`, returning whether it was newly inserted.
+    pub fn insert(&mut self, elem: K) -> bool {
+        let (word_index, mask) = self.word_index_and_mask(elem);
+        let word = &mut self.words[word_index];
+        let new_word = *word | mask;
+        let changed = new_word != *word;
+        *word = new_word;
+        changed
+    }
+
+    /// Removes `elem`, returning whether it was present.
+    pub fn remove(&mut self, elem: K) -> bool {
+        let (word_index, mask) = self.word_index_and_mask(elem);
+        let word = &mut self.words[word_index];
+        let new_word = *word & !mask;
+        let changed = new_word != *word;
+        *word = new_word;
+        changed
+    }
+
+    pub fn contains(&self, elem: K) -> bool {
+        let (word_index, mask) = self.word_index_and_mask(elem);
+        (self.words[word_index] & mask) != 0
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    pub fn iter(&self) -> Iter<K> {
+        Iter::new(&self.words)
+    }
+
+    /// Sets `self` to the union of `self` and `other`, returning whether `self` changed.
+    pub fn union(&mut self, other: &Self) -> bool {
+        debug_assert_eq!(self.domain_size, other.domain_size);
+        bitwise_changed(&mut self.words, &other.words, |a, b| a | b)
+    }
+
+    /// Sets `self` to the intersection of `self` and `other`, returning whether `self` changed.
+    pub fn intersect(&mut self, other: &Self) -> bool {
+        debug_assert_eq!(self.domain_size, other.domain_size);
+        bitwise_changed(&mut self.words, &other.words, |a, b| a & b)
+    }
+
+    /// Removes every element of `other` from `self`, returning whether `self` changed.
+    pub fn subtract(&mut self, other: &Self) -> bool {
+        debug_assert_eq!(self.domain_size, other.domain_size);
+        bitwise_changed(&mut self.words, &other.words, |a, b| a & !b)
+    }
+
+    fn clear_excess_bits(&mut self) {
+        let num_bits_in_last_word = self.domain_size % WORD_BITS;
+        if num_bits_in_last_word > 0 {
+            if let Some(last) = self.words.last_mut() {
+                *last &= (1 << num_bits_in_last_word) - 1;
+            }
+        }
+    }
+
+    fn word_index_and_mask(&self, elem: K) -> (usize, u64) {
+        let elem = elem.index();
+        assert!(elem < self.domain_size);
+        (elem / WORD_BITS, 1 << (elem % WORD_BITS))
+    }
+}
+
+fn bitwise_changed(a: &mut [u64], b: &[u64], op: impl Fn(u64, u64) -> u64) -> bool {
+    let mut changed = false;
+    for (a, &b) in a.iter_mut().zip(b) {
+        let new_a = op(*a, b);
+        if new_a != *a {
+            changed = true;
+            *a = new_a;
+        }
+    }
+    changed
+}
+
+impl<'data, K> IntoIterator for &'data TypeKeyBitSet<K>
+where
+    K: Idx,
+{
+    type Item = K;
+    type IntoIter = Iter<'data, K>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+pub struct Iter<'data, K> {
+    iter: std::slice::Iter<'data, u64>,
+    word: u64,
+    offset: usize,
+    phantom: PhantomData<K>,
+}
+
+impl<'data, K> Iter<'data, K> {
+    fn new(words: &'data [u64]) -> Self {
+        let mut iter = words.iter();
+        let word = iter.next().copied().unwrap_or(0);
+        Self {
+            iter,
+            word,
+            offset: 0,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'data, K> Iterator for Iter<'data, K>
+where
+    K: Idx,
+{
+    type Item = K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.word == 0 {
+            self.word = *self.iter.next()?;
+            self.offset += WORD_BITS;
+        }
+        let lowest_bit = self.word & self.word.wrapping_neg();
+        let index = self.offset + lowest_bit.trailing_zeros() as usize;
+        self.word ^= lowest_bit;
+        Some(K::from_usize(index))
+    }
+}
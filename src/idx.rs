@@ -0,0 +1,98 @@
+/// A type that can be used to index into a [`TypeKeyVec`](crate::TypeKeyVec) or
+/// [`TypeKeySlice`](crate::TypeKeySlice).
+///
+/// Unlike the separate `Into<usize>`/`From<usize>` impls this replaces, a single `Idx` impl
+/// covers both directions, so indexing and [`Enumerate`](crate::Enumerate) agree on the same
+/// conversion.
+pub trait Idx: Copy + Eq + 'static {
+    fn index(self) -> usize;
+
+    fn from_usize(n: usize) -> Self;
+}
+
+impl Idx for usize {
+    #[inline]
+    fn index(self) -> usize {
+        self
+    }
+
+    #[inline]
+    fn from_usize(n: usize) -> Self {
+        n
+    }
+}
+
+/// Generates a `#[repr(transparent)]` newtype wrapper around an integer with an [`Idx`] impl.
+///
+/// ```
+/// type_key_vec::newtype_index! {
+///     pub struct NodeId;
+/// }
+/// ```
+///
+/// The wrapped integer defaults to `u32`, but a different repr can be named explicitly:
+///
+/// ```
+/// type_key_vec::newtype_index! {
+///     pub struct NodeId(u16);
+/// }
+/// ```
+///
+/// An optional `#[max = ...]` attribute makes [`Idx::from_usize`] panic on out-of-range indices:
+///
+/// ```
+/// type_key_vec::newtype_index! {
+///     #[max = 0xFFFF_FF00]
+///     pub struct NodeId;
+/// }
+/// ```
+#[macro_export]
+macro_rules! newtype_index {
+    (
+        $(#[max = $max:expr])?
+        $vis:vis struct $name:ident $(($repr:ty))?;
+    ) => {
+        #[repr(transparent)]
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        $vis struct $name($crate::__newtype_index_repr!($($repr)?));
+
+        impl ::std::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                write!(f, "{}({})", stringify!($name), self.0)
+            }
+        }
+
+        impl $crate::Idx for $name {
+            #[inline]
+            fn index(self) -> usize {
+                self.0 as usize
+            }
+
+            #[inline]
+            fn from_usize(n: usize) -> Self {
+                $(
+                    const MAX: usize = $max;
+                    assert!(
+                        n <= MAX,
+                        "{} index {} exceeds maximum of {}",
+                        stringify!($name),
+                        n,
+                        MAX,
+                    );
+                )?
+                Self(n as _)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_index_repr {
+    () => {
+        u32
+    };
+    ($repr:ty) => {
+        $repr
+    };
+}
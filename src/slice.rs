@@ -1,4 +1,9 @@
-use std::{marker::PhantomData, ops::Index};
+use std::{
+    marker::PhantomData,
+    ops::{Index, IndexMut, Range, RangeFrom, RangeTo},
+};
+
+use crate::Idx;
 
 #[cfg(feature = "rayon")]
 use rayon::iter::IntoParallelIterator;
@@ -7,6 +12,7 @@ use rayon::iter::IntoParallelIterator;
 ///
 /// Used for air-tight indexing with newtypes
 #[derive(Debug)]
+#[repr(transparent)]
 pub struct TypeKeySlice<K, V> {
     phantom: PhantomData<K>,
     inner: [V],
@@ -51,6 +57,14 @@ impl<K, V> TypeKeySlice<K, V> {
         Enumerate::new(self.iter_mut())
     }
 
+    #[inline]
+    pub fn keys(&self) -> Keys<K>
+    where
+        K: Idx,
+    {
+        Keys::new(0..self.inner.len())
+    }
+
     #[inline]
     pub fn as_slice(&self) -> &[V] {
         &self.inner
@@ -64,27 +78,93 @@ impl<K, V> TypeKeySlice<K, V> {
 
 impl<K, V> TypeKeySlice<K, V>
 where
-    K: Into<usize>,
+    K: Idx,
 {
     #[inline]
     pub fn get(&self, key: K) -> Option<&V> {
-        self.inner.get(key.into())
+        self.inner.get(key.index())
     }
 
     #[inline]
     pub fn get_mut(&mut self, key: K) -> Option<&mut V> {
-        self.inner.get_mut(key.into())
+        self.inner.get_mut(key.index())
+    }
+
+    #[inline]
+    pub fn split_at(&self, mid: K) -> (&Self, &Self) {
+        let (left, right) = self.inner.split_at(mid.index());
+        (left.as_ref(), right.as_ref())
     }
 }
 
 impl<K, V> Index<K> for TypeKeySlice<K, V>
 where
-    K: Into<usize>,
+    K: Idx,
 {
     type Output = V;
 
     fn index(&self, index: K) -> &Self::Output {
-        &self.inner[index.into()]
+        &self.inner[index.index()]
+    }
+}
+
+impl<K, V> Index<Range<K>> for TypeKeySlice<K, V>
+where
+    K: Idx,
+{
+    type Output = TypeKeySlice<K, V>;
+
+    fn index(&self, range: Range<K>) -> &Self::Output {
+        self.inner[range.start.index()..range.end.index()].as_ref()
+    }
+}
+
+impl<K, V> IndexMut<Range<K>> for TypeKeySlice<K, V>
+where
+    K: Idx,
+{
+    fn index_mut(&mut self, range: Range<K>) -> &mut Self::Output {
+        self.inner[range.start.index()..range.end.index()].as_mut()
+    }
+}
+
+impl<K, V> Index<RangeFrom<K>> for TypeKeySlice<K, V>
+where
+    K: Idx,
+{
+    type Output = TypeKeySlice<K, V>;
+
+    fn index(&self, range: RangeFrom<K>) -> &Self::Output {
+        self.inner[range.start.index()..].as_ref()
+    }
+}
+
+impl<K, V> IndexMut<RangeFrom<K>> for TypeKeySlice<K, V>
+where
+    K: Idx,
+{
+    fn index_mut(&mut self, range: RangeFrom<K>) -> &mut Self::Output {
+        self.inner[range.start.index()..].as_mut()
+    }
+}
+
+impl<K, V> Index<RangeTo<K>> for TypeKeySlice<K, V>
+where
+    K: Idx,
+{
+    type Output = TypeKeySlice<K, V>;
+
+    fn index(&self, range: RangeTo<K>) -> &Self::Output {
+        self.inner[..range.end.index()].as_ref()
+    }
+}
+
+impl<K, V> IndexMut<RangeTo<K>> for TypeKeySlice<K, V>
+where
+    K: Idx,
+{
+    fn index_mut(&mut self, range: RangeTo<K>) -> &mut Self::Output {
+        self.inner[..range.end.index()].as_mut()
     }
 }
 
@@ -146,6 +226,46 @@ where
     }
 }
 
+pub struct Keys<K> {
+    range: Range<usize>,
+    phantom: PhantomData<K>,
+}
+
+impl<K> Keys<K> {
+    pub(crate) fn new(range: Range<usize>) -> Self {
+        Self {
+            range,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<K> Iterator for Keys<K>
+where
+    K: Idx,
+{
+    type Item = K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(K::from_usize)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<K> ExactSizeIterator for Keys<K> where K: Idx {}
+
+impl<K> DoubleEndedIterator for Keys<K>
+where
+    K: Idx,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range.next_back().map(K::from_usize)
+    }
+}
+
 pub struct Enumerate<K, I> {
     iter: std::iter::Enumerate<I>,
     phantom: PhantomData<K>,
@@ -165,13 +285,13 @@ impl<K, I> Enumerate<K, I> {
 
 impl<K, I> Iterator for Enumerate<K, I>
 where
-    K: From<usize>,
+    K: Idx,
     I: Iterator,
 {
     type Item = (K, I::Item);
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next().map(|(i, v)| (K::from(i), v))
+        self.iter.next().map(|(i, v)| (K::from_usize(i), v))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -181,17 +301,17 @@ where
 
 impl<K, I> ExactSizeIterator for Enumerate<K, I>
 where
-    K: From<usize>,
+    K: Idx,
     I: ExactSizeIterator,
 {
 }
 
 impl<K, I> DoubleEndedIterator for Enumerate<K, I>
 where
-    K: From<usize>,
+    K: Idx,
     I: ExactSizeIterator + DoubleEndedIterator,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
-        self.iter.next_back().map(|(i, v)| (K::from(i), v))
+        self.iter.next_back().map(|(i, v)| (K::from_usize(i), v))
     }
 }
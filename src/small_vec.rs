@@ -0,0 +1,133 @@
+use std::{
+    marker::PhantomData,
+    ops::{Index, IndexMut},
+};
+
+use smallvec::SmallVec;
+
+use crate::{Idx, Keys};
+
+/// A vector that can only be indexed by a specific type, storing up to `N` elements inline
+///
+/// Backed by a [`SmallVec`], so typed vectors that only ever hold a handful of elements stay
+/// allocation-free
+///
+/// Requires the `smallvec` feature to enable `smallvec`'s own `const_generics` feature (needed
+/// for `[V; N]` to satisfy `smallvec::Array` with a generic `N`).
+#[derive(Debug)]
+pub struct SmallTypeKeyVec<K, V, const N: usize> {
+    inner: SmallVec<[V; N]>,
+    phantom: PhantomData<K>,
+}
+
+impl<K, V, const N: usize> SmallTypeKeyVec<K, V, N> {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<V> {
+        self.inner.iter()
+    }
+}
+
+impl<K, V, const N: usize> SmallTypeKeyVec<K, V, N>
+where
+    K: Idx,
+{
+    #[inline]
+    pub fn get(&self, key: K) -> Option<&V> {
+        self.inner.get(key.index())
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, key: K) -> Option<&mut V> {
+        self.inner.get_mut(key.index())
+    }
+
+    /// Returns the key the next [`push`](Self::push) would return.
+    #[inline]
+    pub fn next_key(&self) -> K {
+        K::from_usize(self.inner.len())
+    }
+
+    /// Pushes `value` and returns the key it landed at.
+    #[inline]
+    pub fn push(&mut self, value: V) -> K {
+        let key = self.next_key();
+        self.inner.push(value);
+        key
+    }
+
+    #[inline]
+    pub fn keys(&self) -> Keys<K> {
+        Keys::new(0..self.inner.len())
+    }
+}
+
+impl<K, V, const N: usize> Default for SmallTypeKeyVec<K, V, N> {
+    fn default() -> Self {
+        Self {
+            inner: Default::default(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<K, V: Clone, const N: usize> Clone for SmallTypeKeyVec<K, V, N> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<K, V, const N: usize> Index<K> for SmallTypeKeyVec<K, V, N>
+where
+    K: Idx,
+{
+    type Output = V;
+
+    fn index(&self, index: K) -> &Self::Output {
+        &self.inner[index.index()]
+    }
+}
+
+impl<K, V, const N: usize> IndexMut<K> for SmallTypeKeyVec<K, V, N>
+where
+    K: Idx,
+{
+    fn index_mut(&mut self, index: K) -> &mut Self::Output {
+        &mut self.inner[index.index()]
+    }
+}
+
+impl<K, V, const N: usize> From<SmallVec<[V; N]>> for SmallTypeKeyVec<K, V, N> {
+    fn from(inner: SmallVec<[V; N]>) -> Self {
+        Self {
+            inner,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'data, K, V, const N: usize> IntoIterator for &'data SmallTypeKeyVec<K, V, N> {
+    type Item = &'data V;
+    type IntoIter = std::slice::Iter<'data, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter()
+    }
+}
@@ -1,9 +1,9 @@
 use std::{
     marker::PhantomData,
-    ops::{Deref, Index, IndexMut},
+    ops::{Deref, Index, IndexMut, Range, RangeFrom, RangeTo},
 };
 
-use crate::TypeKeySlice;
+use crate::{Idx, Keys, TypeKeySlice};
 
 #[cfg(feature = "rayon")]
 use rayon::iter::IntoParallelIterator;
@@ -36,11 +36,33 @@ impl<K, V> TypeKeyVec<K, V> {
         }
     }
 
+    /// Creates a `TypeKeyVec` of length `n`, filled with clones of `value`.
+    #[inline]
+    pub fn from_elem_n(value: V, n: usize) -> Self
+    where
+        V: Clone,
+    {
+        Self::from(vec![value; n])
+    }
+
     #[inline]
     pub unsafe fn set_len(&mut self, new_len: usize) {
         self.inner.set_len(new_len);
     }
 
+    #[inline]
+    pub fn resize(&mut self, new_len: usize, value: V)
+    where
+        V: Clone,
+    {
+        self.inner.resize(new_len, value);
+    }
+
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        self.inner.truncate(len);
+    }
+
     #[inline]
     pub fn len(&self) -> usize {
         self.inner.len()
@@ -51,11 +73,6 @@ impl<K, V> TypeKeyVec<K, V> {
         self.inner.is_empty()
     }
 
-    #[inline]
-    pub fn push(&mut self, value: V) {
-        self.inner.push(value);
-    }
-
     #[inline]
     pub fn clear(&mut self) {
         self.inner.clear()
@@ -74,16 +91,45 @@ impl<K, V> TypeKeyVec<K, V> {
 
 impl<K, V> TypeKeyVec<K, V>
 where
-    K: Into<usize>,
+    K: Idx,
 {
     #[inline]
     pub fn get(&self, key: K) -> Option<&V> {
-        self.inner.get(key.into())
+        self.inner.get(key.index())
     }
 
     #[inline]
     pub fn get_mut(&mut self, key: K) -> Option<&mut V> {
-        self.inner.get_mut(key.into())
+        self.inner.get_mut(key.index())
+    }
+
+    /// Returns the key the next [`push`](Self::push) would return.
+    #[inline]
+    pub fn next_key(&self) -> K {
+        K::from_usize(self.inner.len())
+    }
+
+    /// Pushes `value` and returns the key it landed at.
+    #[inline]
+    pub fn push(&mut self, value: V) -> K {
+        let key = self.next_key();
+        self.inner.push(value);
+        key
+    }
+
+    #[inline]
+    pub fn keys(&self) -> Keys<K> {
+        Keys::new(0..self.inner.len())
+    }
+
+    /// Grows the vector if necessary so that `key` is a valid index, filling any new slots
+    /// with `fill_value`, then returns a mutable reference to the element at `key`.
+    pub fn ensure_contains_elem(&mut self, key: K, fill_value: impl FnMut() -> V) -> &mut V {
+        let min_new_len = key.index() + 1;
+        if self.inner.len() < min_new_len {
+            self.inner.resize_with(min_new_len, fill_value);
+        }
+        &mut self.inner[key.index()]
     }
 }
 
@@ -107,21 +153,81 @@ impl<K, V: Clone> Clone for TypeKeyVec<K, V> {
 
 impl<K, V> Index<K> for TypeKeyVec<K, V>
 where
-    K: Into<usize>,
+    K: Idx,
 {
     type Output = V;
 
     fn index(&self, index: K) -> &Self::Output {
-        &self.inner[index.into()]
+        &self.inner[index.index()]
     }
 }
 
 impl<K, V> IndexMut<K> for TypeKeyVec<K, V>
 where
-    K: Into<usize>,
+    K: Idx,
 {
     fn index_mut(&mut self, index: K) -> &mut Self::Output {
-        &mut self.inner[index.into()]
+        &mut self.inner[index.index()]
+    }
+}
+
+impl<K, V> Index<Range<K>> for TypeKeyVec<K, V>
+where
+    K: Idx,
+{
+    type Output = TypeKeySlice<K, V>;
+
+    fn index(&self, range: Range<K>) -> &Self::Output {
+        self.inner[range.start.index()..range.end.index()].as_ref()
+    }
+}
+
+impl<K, V> IndexMut<Range<K>> for TypeKeyVec<K, V>
+where
+    K: Idx,
+{
+    fn index_mut(&mut self, range: Range<K>) -> &mut Self::Output {
+        self.inner[range.start.index()..range.end.index()].as_mut()
+    }
+}
+
+impl<K, V> Index<RangeFrom<K>> for TypeKeyVec<K, V>
+where
+    K: Idx,
+{
+    type Output = TypeKeySlice<K, V>;
+
+    fn index(&self, range: RangeFrom<K>) -> &Self::Output {
+        self.inner[range.start.index()..].as_ref()
+    }
+}
+
+impl<K, V> IndexMut<RangeFrom<K>> for TypeKeyVec<K, V>
+where
+    K: Idx,
+{
+    fn index_mut(&mut self, range: RangeFrom<K>) -> &mut Self::Output {
+        self.inner[range.start.index()..].as_mut()
+    }
+}
+
+impl<K, V> Index<RangeTo<K>> for TypeKeyVec<K, V>
+where
+    K: Idx,
+{
+    type Output = TypeKeySlice<K, V>;
+
+    fn index(&self, range: RangeTo<K>) -> &Self::Output {
+        self.inner[..range.end.index()].as_ref()
+    }
+}
+
+impl<K, V> IndexMut<RangeTo<K>> for TypeKeyVec<K, V>
+where
+    K: Idx,
+{
+    fn index_mut(&mut self, range: RangeTo<K>) -> &mut Self::Output {
+        self.inner[..range.end.index()].as_mut()
     }
 }
 
@@ -134,6 +240,12 @@ impl<K, V> From<Vec<V>> for TypeKeyVec<K, V> {
     }
 }
 
+impl<K, V> FromIterator<V> for TypeKeyVec<K, V> {
+    fn from_iter<I: IntoIterator<Item = V>>(iter: I) -> Self {
+        Self::from(Vec::from_iter(iter))
+    }
+}
+
 impl<K, V> Deref for TypeKeyVec<K, V> {
     type Target = TypeKeySlice<K, V>;
 
@@ -172,7 +284,7 @@ impl<'data, K, V> IntoIterator for &'data mut TypeKeyVec<K, V> {
 #[cfg(feature = "rayon")]
 impl<K, V> IntoParallelIterator for TypeKeyVec<K, V>
 where
-    K: Into<usize>,
+    K: Idx,
     V: Send,
 {
     type Item = V;
@@ -186,7 +298,7 @@ where
 #[cfg(feature = "rayon")]
 impl<'data, K, V> IntoParallelIterator for &'data TypeKeyVec<K, V>
 where
-    K: Into<usize>,
+    K: Idx,
     V: Send + Sync,
 {
     type Item = &'data V;
@@ -200,7 +312,7 @@ where
 #[cfg(feature = "rayon")]
 impl<'data, K, V> IntoParallelIterator for &'data mut TypeKeyVec<K, V>
 where
-    K: Into<usize>,
+    K: Idx,
     V: Send,
 {
     type Item = &'data mut V;